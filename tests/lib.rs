@@ -0,0 +1,10 @@
+#[macro_use]
+mod utils;
+mod prj;
+mod rstest;
+
+use prj::Project;
+
+fn prj() -> Project {
+    Project::new()
+}
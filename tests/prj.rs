@@ -0,0 +1,83 @@
+use std::fs::{create_dir_all, write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+pub struct Project {
+    root: PathBuf,
+    name: String,
+}
+
+fn unique_name() -> String {
+    static COUNTER: AtomicUsize = AtomicUsize::new(0);
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap()
+        .as_nanos();
+    format!(
+        "rstest_test_{}_{}",
+        nanos,
+        COUNTER.fetch_add(1, Ordering::SeqCst)
+    )
+}
+
+impl Project {
+    pub fn new() -> Self {
+        let name = unique_name();
+        let root = std::env::temp_dir().join(&name);
+        create_dir_all(root.join("src")).unwrap();
+
+        write(
+            root.join("Cargo.toml"),
+            format!(
+                r#"[package]
+name = "{name}"
+version = "0.1.0"
+edition = "2018"
+
+[dependencies]
+rstest = {{ path = "{rstest_path}" }}
+"#,
+                name = name,
+                rstest_path = env!("CARGO_MANIFEST_DIR")
+            ),
+        )
+        .unwrap();
+
+        Self { root, name }
+    }
+
+    // Copies `code` into this project's `src/lib.rs`.
+    pub fn set_code_file(self, code: PathBuf) -> Self {
+        let content = std::fs::read_to_string(&code)
+            .unwrap_or_else(|e| panic!("Cannot read resource {:?}: {}", code, e));
+        write(self.root.join("src").join("lib.rs"), content).unwrap();
+        self
+    }
+
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
+    fn cargo(&self, cmd: &str) -> std::io::Result<Output> {
+        Command::new("cargo")
+            .arg(cmd)
+            .current_dir(&self.root)
+            .output()
+    }
+
+    pub fn compile(&self) -> std::io::Result<Output> {
+        self.cargo("build")
+    }
+
+    pub fn run_tests(&self) -> std::io::Result<Output> {
+        self.cargo("test")
+    }
+}
+
+pub fn project_path(res: impl AsRef<Path>) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join(res.as_ref())
+}
@@ -0,0 +1,9 @@
+use rstest::rstest;
+
+
+#[rstest(a, b, case(42), case(1, 2), case(43))] //~ ERROR Wrong case signature: should match the given parameters list.
+fn less(a: u32, b: u32) {}
+
+
+#[rstest(a, case(42, 43), case(12), case(24, 34))] //~ ERROR Wrong case signature: should match the given parameters list.
+fn more(a: u32) {}
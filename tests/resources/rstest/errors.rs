@@ -0,0 +1,49 @@
+use rstest::{rstest, fixture};
+
+#[fixture]
+fn fixture() -> u32 { 42 }
+
+fn error_wrong_type() {
+    let a: u32 = ""; //~ ERROR mismatched types
+}
+
+#[rstest]
+fn error_cannot_resolve_fixture(no_fixture: u32, f: u32) {} //~ ERROR failed to resolve
+
+#[rstest]
+fn error_fixture_wrong_type(fixture: String, f: u32) {} //~ ERROR mismatched types
+
+#[rstest]
+fn error_param_wrong_type(f: &str) {} //~ ERROR mismatched types
+
+#[rstest(f,
+    case(vec![1,2,3].contains(2))) //~ ERROR mismatched types
+)]
+fn error_arbitrary_code(f: bool) {}
+
+
+
+#[rstest(f, case(42), not_a_fixture(24))] //~ ERROR Missed argument: 'not_a_fixture' should be a test function argument.
+fn error_inject_wrong_fixture(f: u32) {}
+
+#[fixture]
+fn f() -> u32 { 42 }
+
+
+
+
+
+
+
+
+
+#[rstest(f, f(42), case(12))] //~ ERROR Duplicate argument: 'f' is already defined.
+fn error_duplicate_fixture_after_case(f: u32) {}
+
+
+#[rstest(f(42), f, case(12))] //~ ERROR Duplicate argument: 'f' is already defined.
+fn error_case_after_duplicate_fixture(f: u32) {}
+
+
+#[rstest(v, f(42), f(42), case(12))] //~ ERROR Duplicate argument: 'f' is already defined.
+fn error_duplicate_fixture_twice(v: u32, f: u32) {}
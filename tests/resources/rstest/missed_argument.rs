@@ -0,0 +1,5 @@
+use rstest::rstest;
+
+
+#[rstest(f, case(42), case(24))] //~ ERROR Missed argument: 'f' should be a test function argument.
+fn it_works() {}
@@ -118,16 +118,22 @@ mod cases {
 
         #[test]
         fn happy_path() {
-            let (output, _) = run_test("missed_argument.rs");
+            let (output, name) = run_test("missed_argument.rs");
             let stderr = output.stderr.str();
 
             assert_ne!(Some(0), output.status.code());
-            assert_in!(stderr, "Missed argument");
-            assert_in!(stderr, "
-                  |
-                4 | #[rstest(f, case(42), case(24))]
-                  |          ^
-                ".unindent());
+            assert_stderr_snapshot("missed_argument.rs", &stderr, &name);
+        }
+
+        #[test]
+        fn happy_path_with_inline_annotations() {
+            let res = "missed_argument.rs";
+            let (output, name) = run_test(res);
+            let source = std::fs::read_to_string(resources(Path::new("rstest").join(res))).unwrap();
+            let stderr = normalize_stderr_default(&output.stderr.str(), &name);
+
+            assert_ne!(Some(0), output.status.code());
+            assert_inline_annotations(res, &source, &stderr);
         }
 
         #[test]
@@ -172,63 +178,25 @@ mod cases {
     mod not_compile_if_a_case_has_a_wrong_signature {
         use super::*;
 
-        use lazy_static::lazy_static;
-        use std::process::Output;
-
-        //noinspection RsTypeCheck
-        fn execute() -> &'static (Output, String) {
-            lazy_static! {
-                static ref OUTPUT: (Output, String) =
-                    run_test("case_with_wrong_args.rs");
-            }
-            assert_ne!(Some(0), OUTPUT.0.status.code(), "Should not compile");
-            &OUTPUT
-        }
-
         #[test]
-        fn with_too_much_arguments() {
-            let (output, _) = execute();
+        fn happy_path() {
+            let res = "case_with_wrong_args.rs";
+            let (output, name) = run_test(res);
             let stderr = output.stderr.str();
 
-            assert_in!(stderr, "
-                  |
-                8 | #[rstest(a, case(42, 43), case(12), case(24, 34))]
-                  |                  ^^^^^^
-                ".unindent());
-
-            assert_in!(stderr, "
-                  |
-                8 | #[rstest(a, case(42, 43), case(12), case(24, 34))]
-                  |                                          ^^^^^^
-                ".unindent());
+            assert_ne!(Some(0), output.status.code(), "Should not compile");
+            assert_stderr_snapshot(res, &stderr, &name);
         }
 
         #[test]
-        fn with_less_arguments() {
-            let (output, _) = execute();
-            let stderr = output.stderr.str();
-
-            assert_in!(stderr, "
-                  |
-                4 | #[rstest(a, b, case(42), case(1, 2), case(43))]
-                  |                     ^^
-                ".unindent());
-
-            assert_in!(stderr, "
-                  |
-                4 | #[rstest(a, b, case(42), case(1, 2), case(43))]
-                  |                                           ^^
-                ".unindent());
-        }
-
-        #[test]
-        fn and_reports_all_errors() {
-            let (output, _) = execute();
-            let stderr = output.stderr.str();
-
-            // Exactly 4 cases are wrong
-            assert_eq!(4, stderr.count("Wrong case signature: should match the given parameters list."),
-                       "Should contain message exactly 4 occurrences in error message:\n{}", stderr);
+        fn happy_path_with_inline_annotations() {
+            let res = "case_with_wrong_args.rs";
+            let (output, name) = run_test(res);
+            let source = std::fs::read_to_string(resources(Path::new("rstest").join(res))).unwrap();
+            let stderr = normalize_stderr_default(&output.stderr.str(), &name);
+
+            assert_ne!(Some(0), output.status.code(), "Should not compile");
+            assert_inline_annotations(res, &source, &stderr);
         }
     }
 
@@ -303,139 +271,26 @@ mod cases {
 
     mod should_show_correct_errors {
         use super::*;
-        use lazy_static::lazy_static;
-        use std::process::Output;
-
-        //noinspection RsTypeCheck
-        fn execute() -> &'static (Output, String) {
-            lazy_static! {
-                static ref OUTPUT: (Output, String) =
-                    run_test("errors.rs");
-            }
-            &OUTPUT
-        }
-
-        #[test]
-        fn if_no_fixture() {
-            let (output, name) = execute();
-
-            assert_in!(output.stderr.str(), format!("
-                error[E0433]: failed to resolve: use of undeclared type or module `no_fixture`
-                  --> {}/src/lib.rs:11:33
-                   |
-                11 | fn error_cannot_resolve_fixture(no_fixture: u32, f: u32) {{}}", name).unindent());
-        }
-
-        #[test]
-        fn if_inject_wrong_fixture() {
-            let (output, name) = execute();
-
-            assert_in!(output.stderr.str(), format!("
-                error: Missed argument: 'not_a_fixture' should be a test function argument.
-                  --> {}/src/lib.rs:26:23
-                   |
-                26 | #[rstest(f, case(42), not_a_fixture(24))]
-                   |                       ^^^^^^^^^^^^^
-                ", name).unindent());
-        }
 
         #[test]
-        fn if_wrong_type() {
-            let (output, name) = execute();
-
-            assert_in!(output.stderr.str(), format!(r#"
-                error[E0308]: mismatched types
-                 --> {}/src/lib.rs:7:18
-                  |
-                7 |     let a: u32 = "";
-                  |                  ^^ expected u32, found reference
-                  |
-                  = note: expected type `u32`
-                             found type `&'static str`
-                "#, name).unindent());
-        }
-
-        #[test]
-        fn if_wrong_type_fixture() {
-            let (output, name) = execute();
-
-            assert_in!(output.stderr.str(), format!("
-                error[E0308]: mismatched types
-                  --> {}/src/lib.rs:14:29
-                   |
-                14 | fn error_fixture_wrong_type(fixture: String, f: u32) {{}}
-                   |                             ^^^^^^^
-                   |                             |
-                   |                             expected struct `std::string::String`, found u32
-                   |                             help: try using a conversion method: `fixture.to_string()`
-                   |
-                   = note: expected type `std::string::String`
-                              found type `u32`
-                ", name).unindent());
-        }
-
-        #[test]
-        fn if_wrong_type_param() {
-            let (output, name) = execute();
-
-            assert_in!(output.stderr.str(), format!("
-                error[E0308]: mismatched types
-                  --> {}/src/lib.rs:17:27
-                   |
-                17 | fn error_param_wrong_type(f: &str) {{}}", name).unindent());
-        }
-
-        #[test]
-        fn if_arbitrary_rust_code_has_some_errors() {
-            let (output, name) = execute();
-
-            assert_in!(output.stderr.str(), format!("
-                error[E0308]: mismatched types
-                  --> {}/src/lib.rs:20:31
-                   |
-                20 |     case(vec![1,2,3].contains(2)))
-                   |                               ^
-                   |                               |",
-                   name).unindent());
-        }
-
-        #[test]
-        fn if_inject_a_fixture_that_is_already_a_case() {
-            let (output, name) = execute();
-
-            assert_in!(output.stderr.str(), format!("
-                error: Duplicate argument: 'f' is already defined.
-                  --> {}/src/lib.rs:40:13
-                   |
-                40 | #[rstest(f, f(42), case(12))]
-                   |             ^",
-                   name).unindent());
-        }
+        fn happy_path() {
+            let res = "errors.rs";
+            let (output, name) = run_test(res);
+            let stderr = output.stderr.str();
 
-        #[test]
-        fn if_define_case_that_is_already_an_injected_fixture() {
-            let (output, name) = execute();
-
-            assert_in!(output.stderr.str(), format!("
-                error: Duplicate argument: 'f' is already defined.
-                  --> {}/src/lib.rs:44:17
-                   |
-                44 | #[rstest(f(42), f, case(12))]
-                   |                 ^",
-                   name).unindent());
+            assert_ne!(Some(0), output.status.code(), "Should not compile");
+            assert_stderr_snapshot(res, &stderr, &name);
         }
 
         #[test]
-        fn if_inject_a_fixture_more_than_once() {
-            let (output, name) = execute();
-
-            assert_in!(output.stderr.str(), format!("
-                error: Duplicate argument: 'f' is already defined.
-                  --> {}/src/lib.rs:48:20
-                   |
-                48 | #[rstest(v, f(42), f(42), case(12))]
-                   |                    ^",
-                   name).unindent());
+        fn happy_path_with_inline_annotations() {
+            let res = "errors.rs";
+            let (output, name) = run_test(res);
+            let source = std::fs::read_to_string(resources(Path::new("rstest").join(res))).unwrap();
+            let stderr = normalize_stderr_default(&output.stderr.str(), &name);
+
+            assert_ne!(Some(0), output.status.code(), "Should not compile");
+            assert_inline_annotations(res, &source, &stderr);
         }
     }
 }
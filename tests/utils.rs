@@ -0,0 +1,270 @@
+use std::path::{Path, PathBuf};
+use std::process::Output;
+
+use regex::Regex;
+
+// Resolves a resource file under `tests/resources`.
+pub fn resources(res: impl AsRef<Path>) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("resources")
+        .join(res)
+}
+
+pub trait OutputStr {
+    fn str(&self) -> std::borrow::Cow<str>;
+}
+
+impl OutputStr for Vec<u8> {
+    fn str(&self) -> std::borrow::Cow<str> {
+        String::from_utf8_lossy(self)
+    }
+}
+
+pub trait Occurrences {
+    fn count(&self, needle: &str) -> usize;
+}
+
+impl Occurrences for str {
+    fn count(&self, needle: &str) -> usize {
+        self.matches(needle).count()
+    }
+}
+
+impl<'a> Occurrences for std::borrow::Cow<'a, str> {
+    fn count(&self, needle: &str) -> usize {
+        self.as_ref().count(needle)
+    }
+}
+
+#[macro_export]
+macro_rules! assert_in {
+    ($haystack:expr, $needle:expr) => {{
+        let haystack = $haystack.to_string();
+        let needle = $needle.to_string();
+        assert!(
+            haystack.contains(&needle),
+            "Cannot find\n{}\ninto\n{}",
+            needle,
+            haystack
+        );
+    }};
+}
+
+// Occurrences matching `pattern` are replaced with `replacement`.
+pub struct Filter {
+    pattern: Regex,
+    replacement: &'static str,
+}
+
+impl Filter {
+    pub fn new(pattern: Regex, replacement: &'static str) -> Self {
+        Self { pattern, replacement }
+    }
+
+    fn apply(&self, text: &str) -> String {
+        self.pattern.replace_all(text, self.replacement).into_owned()
+    }
+}
+
+// Normalizes the temp project's absolute path and generated crate name to
+// $DIR/$CRATE, and this checkout's own path (as embedded in the `rstest =
+// { path = ".." }` dev-dependency, e.g. in a "Compiling rstest v.. (..)"
+// line) to $RSTEST, so assertions are portable between a contributor's
+// machine and CI.
+fn default_filters(project_name: &str) -> Vec<Filter> {
+    vec![
+        Filter::new(
+            Regex::new(&format!(r#"[^\s"]*[\\/]{}"#, regex::escape(project_name))).unwrap(),
+            "$DIR",
+        ),
+        Filter::new(Regex::new(&regex::escape(project_name)).unwrap(), "$CRATE"),
+        Filter::new(
+            Regex::new(&regex::escape(env!("CARGO_MANIFEST_DIR"))).unwrap(),
+            "$RSTEST",
+        ),
+    ]
+}
+
+// Applies `filters`, in order, to `stderr`.
+pub fn normalize_stderr(stderr: &str, filters: &[Filter]) -> String {
+    filters
+        .iter()
+        .fold(stderr.to_owned(), |text, f| f.apply(&text))
+}
+
+// Applies the default filters for `project_name` to `stderr`.
+pub fn normalize_stderr_default(stderr: &str, project_name: &str) -> String {
+    normalize_stderr(stderr, &default_filters(project_name))
+}
+
+// RSTEST_BLESS=1 rewrites the snapshot from the freshly captured stderr
+// instead of asserting against it (same idea as ui_test's bless mode).
+const BLESS_ENV: &str = "RSTEST_BLESS";
+
+fn snapshot_path(res: &str) -> PathBuf {
+    let stem = res.trim_end_matches(".rs");
+    resources(Path::new("rstest").join(format!("{}.stderr", stem)))
+}
+
+// cargo's own "Compiling"/"Finished"/"could not compile" lines aren't part
+// of the diagnostic and churn on every run (fresh temp dir, full rebuild),
+// so they're dropped before a snapshot ever sees them.
+fn strip_build_noise(stderr: &str) -> String {
+    stderr
+        .lines()
+        .filter(|line| {
+            let line = line.trim_start();
+            !line.starts_with("Compiling")
+                && !line.starts_with("Finished")
+                && !line.starts_with("error: could not compile")
+                && !line.starts_with("error: aborting due to")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// Compares normalized `stderr` against the `<res>.stderr` snapshot, or
+// writes it with RSTEST_BLESS=1 set.
+pub fn assert_stderr_snapshot(res: &str, stderr: &str, project_name: &str) {
+    let path = snapshot_path(res);
+    let stderr = normalize_stderr_default(&strip_build_noise(stderr), project_name);
+
+    if std::env::var_os(BLESS_ENV).is_some() {
+        std::fs::write(&path, &stderr)
+            .unwrap_or_else(|e| panic!("Cannot write snapshot {:?}: {}", path, e));
+        return;
+    }
+
+    let expected = std::fs::read_to_string(&path).unwrap_or_else(|e| {
+        panic!(
+            "Cannot read snapshot {:?}: {}\nRun with {}=1 to create it.",
+            path, e, BLESS_ENV
+        )
+    });
+
+    assert_eq!(
+        expected.trim(),
+        stderr.trim(),
+        "stderr doesn't match snapshot {:?}\nRun with {}=1 to update it.",
+        path,
+        BLESS_ENV
+    );
+}
+
+// A `//~ ERROR <substring>` annotation, pinned to the line it expects a
+// diagnostic for.
+struct ExpectedError {
+    line: usize,
+    message: String,
+}
+
+const ANNOTATION_MARKER: &str = "//~ ERROR";
+
+fn parse_annotations(source: &str) -> Vec<ExpectedError> {
+    source
+        .lines()
+        .enumerate()
+        .filter_map(|(i, line)| {
+            line.find(ANNOTATION_MARKER).map(|idx| ExpectedError {
+                line: i + 1,
+                message: line[idx + ANNOTATION_MARKER.len()..].trim().to_owned(),
+            })
+        })
+        .collect()
+}
+
+struct ActualError {
+    line: usize,
+    message: String,
+}
+
+// Reads the line number off the first "N | <source>" gutter within five
+// lines after the error header, not off the "--> file:line:col" pointer.
+fn parse_actual_errors(stderr: &str) -> Vec<ActualError> {
+    let gutter = Regex::new(r"^\s*(\d+)\s*\|").unwrap();
+    let lines: Vec<&str> = stderr.lines().collect();
+
+    lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| {
+            let message = line.strip_prefix("error")?.splitn(2, ": ").nth(1)?;
+            let line_no = lines[i + 1..]
+                .iter()
+                .take(5)
+                .find_map(|l| gutter.captures(l)?.get(1)?.as_str().parse().ok())?;
+            Some(ActualError {
+                line: line_no,
+                message: message.to_owned(),
+            })
+        })
+        .collect()
+}
+
+// Every annotated line must have a matching diagnostic, and every
+// diagnostic must sit on an annotated line (an un-annotated error fails
+// too, unlike assert_in!).
+pub fn assert_inline_annotations(res: &str, source: &str, stderr: &str) {
+    let expected = parse_annotations(source);
+    let actual = parse_actual_errors(stderr);
+
+    for exp in &expected {
+        assert!(
+            actual
+                .iter()
+                .any(|act| act.line == exp.line && act.message.contains(&exp.message)),
+            "{}:{}: expected error {:?}, not found in:\n{}",
+            res,
+            exp.line,
+            exp.message,
+            stderr
+        );
+    }
+
+    for act in &actual {
+        assert!(
+            expected.iter().any(|exp| exp.line == act.line),
+            "{}:{}: un-annotated error {:?}",
+            res,
+            act.line,
+            act.message
+        );
+    }
+}
+
+// Matches the rendered test names in `cargo test`'s output against the
+// pass/fail outcome expected for each case.
+pub struct TestResults {
+    oks: Vec<String>,
+    fails: Vec<String>,
+}
+
+impl TestResults {
+    pub fn new() -> Self {
+        Self {
+            oks: Default::default(),
+            fails: Default::default(),
+        }
+    }
+
+    pub fn ok(mut self, name: &str) -> Self {
+        self.oks.push(name.to_owned());
+        self
+    }
+
+    pub fn fail(mut self, name: &str) -> Self {
+        self.fails.push(name.to_owned());
+        self
+    }
+
+    pub fn assert(self, output: Output) {
+        let out = output.stdout.str().to_string();
+        for name in &self.oks {
+            assert_in!(out, format!("test {} ... ok", name));
+        }
+        for name in &self.fails {
+            assert_in!(out, format!("test {} ... FAILED", name));
+        }
+    }
+}